@@ -1,3 +1,5 @@
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
 use pyo3::prelude::*;
 
 /// 递归计算斐波那契数列
@@ -23,14 +25,145 @@ fn fibonacci_iter(n: u64) -> u64 {
     b
 }
 
-/// 快速排序算法
-fn quick_sort(arr: &mut [f64]) {
-    if arr.len() <= 1 {
-        return;
+/// 任意精度迭代计算斐波那契数列,不会像 u64 版本那样在 F(93) 之后溢出
+fn fibonacci_big(n: u64) -> BigUint {
+    if n <= 1 {
+        return BigUint::from(n);
+    }
+    let mut a = BigUint::zero();
+    let mut b = BigUint::one();
+    for _ in 2..=n {
+        let temp = &a + &b;
+        a = b;
+        b = temp;
+    }
+    b
+}
+
+/// 快速倍增法的核心递归,返回 (F(k), F(k+1)) 二元组
+fn fib_fast_doubling_pair(k: u64) -> (BigUint, BigUint) {
+    if k == 0 {
+        return (BigUint::zero(), BigUint::one());
+    }
+    let (a, b) = fib_fast_doubling_pair(k / 2);
+    // F(2k) = F(k) * (2*F(k+1) - F(k)), F(2k+1) = F(k)^2 + F(k+1)^2
+    let two_b_minus_a = (&b * 2u32) - &a;
+    let f2k = &a * &two_b_minus_a;
+    let f2k1 = (&a * &a) + (&b * &b);
+    if k.is_multiple_of(2) {
+        (f2k, f2k1)
+    } else {
+        let f2k2 = &f2k + &f2k1;
+        (f2k1, f2k2)
+    }
+}
+
+/// 快速倍增法计算斐波那契数列,时间复杂度 O(log n),配合 BigUint 保持精确
+fn fibonacci_fast(n: u64) -> BigUint {
+    fib_fast_doubling_pair(n).0
+}
+
+/// 单次遍历生成斐波那契数列的前 n 项,O(n),避免重复调用 fibonacci_iter 造成的 O(n^2);
+/// 使用 BigUint 累加,避免像 u64 版本那样在 F(93) 之后溢出
+fn fibonacci_seq(n: u64) -> Vec<BigUint> {
+    let mut seq = Vec::with_capacity(n as usize);
+    let mut a = BigUint::zero();
+    let mut b = BigUint::one();
+    for _ in 0..n {
+        seq.push(a.clone());
+        let temp = &a + &b;
+        a = b;
+        b = temp;
+    }
+    seq
+}
+
+/// Zeckendorf 分解:贪心地从最大的不超过 n 的斐波那契数开始选取,
+/// 保证结果中不存在两个相邻的斐波那契数
+fn zeckendorf(n: u64) -> Vec<u64> {
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let mut fibs = vec![0u64, 1u64];
+    loop {
+        let next = match fibs[fibs.len() - 1].checked_add(fibs[fibs.len() - 2]) {
+            Some(next) if next <= n => next,
+            _ => break,
+        };
+        fibs.push(next);
+    }
+
+    let mut remaining = n;
+    let mut terms = Vec::new();
+    for &f in fibs.iter().rev() {
+        if f != 0 && f <= remaining {
+            terms.push(f);
+            remaining -= f;
+        }
+        if remaining == 0 {
+            break;
+        }
+    }
+    terms
+}
+
+/// 惰性斐波那契序列,每次 `__next__` 只推进一步,可配合 `itertools.islice` 使用
+#[pyclass]
+struct FibSequence {
+    a: BigUint,
+    b: BigUint,
+}
+
+#[pymethods]
+impl FibSequence {
+    #[new]
+    fn new() -> Self {
+        FibSequence {
+            a: BigUint::zero(),
+            b: BigUint::one(),
+        }
+    }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
     }
-    let pivot = arr[arr.len() - 1];
+
+    fn __next__(mut slf: PyRefMut<'_, Self>) -> Option<BigUint> {
+        let current = slf.a.clone();
+        let next = &slf.a + &slf.b;
+        slf.a = slf.b.clone();
+        slf.b = next;
+        Some(current)
+    }
+}
+
+/// 将三个候选下标(首、中、尾)按值排序,取中位数下标作为基准,
+/// 避免已排序/逆序输入退化为 O(n^2)
+fn median_of_three(arr: &mut [f64]) -> usize {
+    let len = arr.len();
+    let mid = len / 2;
+    let last = len - 1;
+
+    if arr[mid] < arr[0] {
+        arr.swap(0, mid);
+    }
+    if arr[last] < arr[0] {
+        arr.swap(0, last);
+    }
+    if arr[last] < arr[mid] {
+        arr.swap(mid, last);
+    }
+    arr.swap(mid, last);
+    last
+}
+
+/// 以 arr[arr.len() - 1] 为基准做 Lomuto 分区,返回基准最终所在下标
+fn partition(arr: &mut [f64]) -> usize {
+    let pivot_index = median_of_three(arr);
+    let pivot = arr[pivot_index];
     let mut i = 0;
-    
+
     for j in 0..arr.len() - 1 {
         if arr[j] <= pivot {
             arr.swap(i, j);
@@ -38,9 +171,77 @@ fn quick_sort(arr: &mut [f64]) {
         }
     }
     arr.swap(i, arr.len() - 1);
-    
-    quick_sort(&mut arr[0..i]);
-    quick_sort(&mut arr[i + 1..]);
+    i
+}
+
+/// 原地堆排序,作为递归过深时的兜底,保证最坏情况下仍是 O(n log n)
+fn heap_sort(arr: &mut [f64]) {
+    let len = arr.len();
+    for start in (0..len / 2).rev() {
+        sift_down(arr, start, len);
+    }
+    for end in (1..len).rev() {
+        arr.swap(0, end);
+        sift_down(arr, 0, end);
+    }
+}
+
+fn sift_down(arr: &mut [f64], start: usize, end: usize) {
+    let mut root = start;
+    loop {
+        let mut child = 2 * root + 1;
+        if child >= end {
+            break;
+        }
+        if child + 1 < end && arr[child] < arr[child + 1] {
+            child += 1;
+        }
+        if arr[root] < arr[child] {
+            arr.swap(root, child);
+            root = child;
+        } else {
+            break;
+        }
+    }
+}
+
+/// 内省式快速排序:中位数选取基准,递归深度超过 2*log2(len) 后退化为堆排序,
+/// 保证最坏情况下也是 O(n log n)
+fn introsort(arr: &mut [f64], depth_limit: u32) {
+    if arr.len() <= 1 {
+        return;
+    }
+    if depth_limit == 0 {
+        heap_sort(arr);
+        return;
+    }
+
+    let pivot_index = partition(arr);
+    let (left, right) = arr.split_at_mut(pivot_index);
+    introsort(left, depth_limit - 1);
+    introsort(&mut right[1..], depth_limit - 1);
+}
+
+/// 快速排序算法。对已排序/逆序等常见输入使用中位数基准避免 O(n^2),
+/// 并在递归过深时回退到堆排序;NaN 没有全序关系,统一排到末尾再对其余元素排序
+fn quick_sort(arr: &mut [f64]) {
+    let nan_count = arr.iter().filter(|x| x.is_nan()).count();
+    if nan_count > 0 {
+        let mut i = 0;
+        for j in 0..arr.len() {
+            if !arr[j].is_nan() {
+                arr.swap(i, j);
+                i += 1;
+            }
+        }
+    }
+    let sortable_len = arr.len() - nan_count;
+    let depth_limit = if sortable_len > 0 {
+        2 * (sortable_len as f64).log2().ceil() as u32
+    } else {
+        0
+    };
+    introsort(&mut arr[..sortable_len], depth_limit);
 }
 
 /// Python模块定义
@@ -58,6 +259,30 @@ fn fib_rust(m: &Bound<'_, PyModule>) -> PyResult<()> {
         Ok(fibonacci_iter(n))
     }
     
+    /// 任意精度计算斐波那契数列,返回原生 Python int,支持 F(1000) 等大索引
+    #[pyfn(m)]
+    fn fibonacci_big_py(_py: Python, n: u64) -> PyResult<BigUint> {
+        Ok(fibonacci_big(n))
+    }
+
+    /// 快速倍增法计算斐波那契数列,O(log n),可在毫秒级算出 F(1_000_000)
+    #[pyfn(m)]
+    fn fibonacci_fast_py(_py: Python, n: u64) -> PyResult<BigUint> {
+        Ok(fibonacci_fast(n))
+    }
+
+    /// 单次遍历返回斐波那契数列的前 n 项
+    #[pyfn(m)]
+    fn fibonacci_seq_py(_py: Python, n: u64) -> PyResult<Vec<BigUint>> {
+        Ok(fibonacci_seq(n))
+    }
+
+    /// Zeckendorf 分解,返回和为 n 且两两不相邻的斐波那契数集合
+    #[pyfn(m)]
+    fn zeckendorf_py(_py: Python, n: u64) -> PyResult<Vec<u64>> {
+        Ok(zeckendorf(n))
+    }
+
     /// 快速排序算法
     #[pyfn(m)]
     fn quick_sort_py(_py: Python, mut arr: Vec<f64>) -> PyResult<Vec<f64>> {
@@ -65,5 +290,74 @@ fn fib_rust(m: &Bound<'_, PyModule>) -> PyResult<()> {
         Ok(arr)
     }
     
+    m.add_class::<FibSequence>()?;
+
     Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zeckendorf_of_zero_is_empty() {
+        assert_eq!(zeckendorf(0), Vec::<u64>::new());
+    }
+
+    #[test]
+    fn zeckendorf_of_one_is_one() {
+        assert_eq!(zeckendorf(1), vec![1]);
+    }
+
+    #[test]
+    fn zeckendorf_picks_non_adjacent_fibonacci_numbers() {
+        // naive greedy on the duplicated 1,1 entries could double-count the
+        // leading 1 instead of stopping at 3 + 1
+        assert_eq!(zeckendorf(4), vec![3, 1]);
+    }
+
+    #[test]
+    fn zeckendorf_does_not_off_by_one_near_a_fibonacci_boundary() {
+        // 89 is itself a Fibonacci number; the `next <= n` guard must stop the
+        // table exactly there instead of overshooting by one term
+        assert_eq!(zeckendorf(89), vec![89]);
+        assert_eq!(zeckendorf(88), vec![55, 21, 8, 3, 1]);
+    }
+
+    #[test]
+    fn fibonacci_fast_matches_fibonacci_big_across_a_spread_of_n() {
+        for n in [0, 1, 2, 3, 4, 10, 11, 50, 93, 100] {
+            assert_eq!(
+                fibonacci_fast(n),
+                fibonacci_big(n),
+                "mismatch at n={n}"
+            );
+        }
+    }
+
+    #[test]
+    fn quick_sort_handles_large_sorted_input_without_stack_overflow() {
+        let mut arr: Vec<f64> = (0..10_000).map(|i| i as f64).collect();
+        quick_sort(&mut arr);
+        let expected: Vec<f64> = (0..10_000).map(|i| i as f64).collect();
+        assert_eq!(arr, expected);
+    }
+
+    #[test]
+    fn quick_sort_handles_large_reverse_sorted_input_without_stack_overflow() {
+        let mut arr: Vec<f64> = (0..10_000).rev().map(|i| i as f64).collect();
+        quick_sort(&mut arr);
+        let expected: Vec<f64> = (0..10_000).map(|i| i as f64).collect();
+        assert_eq!(arr, expected);
+    }
+
+    #[test]
+    fn quick_sort_pushes_nan_to_the_tail_and_sorts_the_rest() {
+        let mut arr = vec![3.0, f64::NAN, 1.0, f64::NAN, 2.0];
+        quick_sort(&mut arr);
+        let (sortable, nans) = arr.split_at(3);
+        assert_eq!(sortable, [1.0, 2.0, 3.0]);
+        assert_eq!(nans.len(), 2);
+        assert!(nans.iter().all(|x| x.is_nan()));
+    }
 }
\ No newline at end of file